@@ -1,29 +1,79 @@
 pub mod route;
 
-pub use route::{Parser, Route};
+use route::trie::Node;
 
-#[derive(Debug, Clone, PartialEq)]
+pub use route::{Parser, Route, TrailingSlash};
+
+/// A collection of [`Route`]s, matched via a radix trie keyed on path
+/// segments so that lookup is roughly O(segments) regardless of how many
+/// routes are registered, rather than scanning every route in turn.
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Routes {
-    routes: Vec<Route>,
+    root: Node,
+    trailing_slash: TrailingSlash,
 }
 
 impl Routes {
     pub fn new() -> Routes {
-        Routes { routes: Vec::new() }
+        Routes::default()
     }
 
     pub fn add(&mut self, route: Route) {
-        self.routes.push(route);
+        self.root.insert(&route.path.clone(), route);
+    }
+
+    /// Mounts every route in `routes` under `prefix`, via [`Route::join`].
+    /// Lets a large app assemble its route table out of per-module
+    /// [`Routes`] built with paths relative to where they'll be mounted.
+    ///
+    /// `prefix` is parsed with a default [`Parser`], so it can only use the
+    /// built-in param types, whatever `Parser` built `routes`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix` does not parse with a default [`Parser`].
+    pub fn mount(&mut self, prefix: &str, routes: Routes) {
+        let prefix_route = Parser::default()
+            .route("mount-prefix", prefix)
+            .expect("mount prefix should be a valid route path");
+
+        for route in routes.root.routes() {
+            self.add(prefix_route.join(route));
+        }
+    }
+
+    /// Sets the trailing-slash policy used to normalize incoming paths
+    /// before matching. Should mirror the [`Parser`]'s policy used to build
+    /// the routes added here.
+    pub fn set_trailing_slash(&mut self, trailing_slash: TrailingSlash) {
+        self.trailing_slash = trailing_slash;
+    }
+
+    /// Strips the leading `/`, and - outside [`TrailingSlash::Strict`] - a
+    /// trailing `/` too, matching how [`Parser::route`] normalizes the
+    /// routes' own stored segments.
+    fn clean_path<'a>(&self, path: &'a str) -> &'a str {
+        let clean_path = path.strip_prefix('/').unwrap_or(path);
+        if self.trailing_slash == TrailingSlash::Strict {
+            clean_path
+        } else {
+            clean_path.strip_suffix('/').unwrap_or(clean_path)
+        }
     }
 
     pub fn find(&self, path: &str) -> Option<&Route> {
-        self.routes.iter().find(|&route| route.check(path))
+        let clean_path = self.clean_path(path);
+        let parts = clean_path.split('/').collect::<Vec<&str>>();
+        self.root.find(&parts)
     }
-}
 
-impl Default for Routes {
-    fn default() -> Self {
-        Self::new()
+    /// Like [`Routes::find`], but also reports whether the matched route's
+    /// [`TrailingSlash::Redirect`] policy wants the caller to 301 to the
+    /// canonical form.
+    pub fn find_redirect(&self, path: &str) -> Option<(&Route, bool)> {
+        let route = self.find(path)?;
+        let redirect = route.check_redirect(path)?;
+        Some((route, redirect))
     }
 }
 
@@ -64,4 +114,77 @@ mod tests {
         assert_eq!(Some(&game_route), routes.find("/game/abc/"));
         assert_eq!(None, routes.find("/game/123"));
     }
+
+    #[test]
+    fn test_routes_trailing_slash_ignore() {
+        let mut parser = Parser::default();
+        parser.set_trailing_slash(route::TrailingSlash::Ignore);
+
+        let mut routes = Routes::new();
+        routes.set_trailing_slash(route::TrailingSlash::Ignore);
+
+        let user_route = parser
+            .route("user-by-id", "/user/<id:int>/")
+            .expect("route should parse");
+        routes.add(user_route.clone());
+
+        assert_eq!(Some(&user_route), routes.find("/user/123/"));
+        assert_eq!(Some(&user_route), routes.find("/user/123"));
+    }
+
+    #[test]
+    fn test_routes_find_redirect() {
+        let mut parser = Parser::default();
+        parser.set_trailing_slash(route::TrailingSlash::Redirect);
+
+        let mut routes = Routes::new();
+        routes.set_trailing_slash(route::TrailingSlash::Redirect);
+
+        let user_route = parser
+            .route("user-by-id", "/user/<id:int>/")
+            .expect("route should parse");
+        routes.add(user_route.clone());
+
+        assert_eq!(
+            routes.find_redirect("/user/123/"),
+            Some((&user_route, false))
+        );
+        assert_eq!(
+            routes.find_redirect("/user/123"),
+            Some((&user_route, true))
+        );
+        assert_eq!(routes.find_redirect("/user/abc/"), None);
+    }
+
+    #[test]
+    fn test_mount() {
+        let parser = Parser::default();
+
+        let mut api_routes = Routes::new();
+        let users_route = parser
+            .route("users", "/users/<id:int>/")
+            .expect("route should parse");
+        let clubs_route = parser
+            .route("clubs", "/clubs/<id:uuid>/")
+            .expect("route should parse");
+        api_routes.add(users_route.clone());
+        api_routes.add(clubs_route.clone());
+
+        let mut routes = Routes::new();
+        routes.mount("/api", api_routes);
+
+        let prefix = parser.route("api", "/api").expect("route should parse");
+        assert_eq!(
+            routes.find("/api/users/123/"),
+            Some(&prefix.join(&users_route))
+        );
+        assert_eq!(
+            routes
+                .find("/api/clubs/36be8705-6c31-45d7-9321-d56cc07b50d9/")
+                .and_then(|route| route
+                    .parse_params("/api/clubs/36be8705-6c31-45d7-9321-d56cc07b50d9/")),
+            Some(vec!["36be8705-6c31-45d7-9321-d56cc07b50d9".to_string()])
+        );
+        assert_eq!(routes.find("/users/123/"), None);
+    }
 }
@@ -1,16 +1,30 @@
+use std::collections::HashMap;
+
 use thiserror::Error;
 
 pub use crate::route::parse::Parser;
 
+#[cfg(feature = "serde")]
+pub mod de;
 pub mod param_type;
 pub mod parse;
+pub mod quoter;
+pub mod trailing_slash;
+pub(crate) mod trie;
 
 pub use param_type::ParamType;
+pub use quoter::Quoter;
+pub use trailing_slash::TrailingSlash;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Route {
     pub name: String,
     pub path: Vec<Segment>,
+    quoter: Quoter,
+    trailing_slash: TrailingSlash,
+    /// Whether this route's original definition ended in a `/`, used by
+    /// `TrailingSlash::Redirect` to report the canonical form.
+    has_trailing_slash: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -18,6 +32,9 @@ pub enum Segment {
     Empty,
     Constant(String),
     Param(Param),
+    /// A tail segment (`<name:*>`) that greedily captures the remainder of
+    /// the path, slashes and all. Always the last segment of a route.
+    CatchAll(Param),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -48,14 +65,19 @@ impl Route {
     /// assert!(!route.check("/user/abc/"));
     /// ```
     pub fn check(&self, path: &str) -> bool {
-        let clean_path: &str = path.strip_prefix('/').unwrap_or(path);
+        let clean_path: &str = self.clean_path(path);
         let parts = clean_path.split('/').collect::<Vec<&str>>();
 
-        if parts.len() != self.path.len() {
-            return false;
-        }
+        let mut parts = parts.iter();
+        for segment in self.path.iter() {
+            if let Segment::CatchAll(_) = segment {
+                return true;
+            }
+
+            let Some(part) = parts.next() else {
+                return false;
+            };
 
-        for (part, segment) in parts.iter().zip(self.path.iter()) {
             match segment {
                 Segment::Empty => {
                     if !part.is_empty() {
@@ -72,10 +94,62 @@ impl Route {
                         return false;
                     }
                 }
+                Segment::CatchAll(_) => unreachable!("handled above"),
             }
         }
 
-        true
+        parts.next().is_none()
+    }
+
+    /// Like [`Route::check`], but under [`TrailingSlash::Redirect`] also
+    /// reports whether `path`'s trailing slash differs from this route's
+    /// canonical form, so the caller can issue a 301 to fix it up.
+    ///
+    /// Returns `None` if `path` doesn't match at all, `Some(true)` if it
+    /// matches but should redirect, and `Some(false)` if it matches and is
+    /// already canonical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use routem::{Parser, Route, TrailingSlash};
+    ///
+    /// let mut parser = Parser::default();
+    /// parser.set_trailing_slash(TrailingSlash::Redirect);
+    /// let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+    ///
+    /// assert_eq!(route.check_redirect("/user/123/"), Some(false));
+    /// assert_eq!(route.check_redirect("/user/123"), Some(true));
+    /// assert_eq!(route.check_redirect("/user/abc/"), None);
+    /// ```
+    pub fn check_redirect(&self, path: &str) -> Option<bool> {
+        if !self.check(path) {
+            return None;
+        }
+
+        let clean_path = path.strip_prefix('/').unwrap_or(path);
+        let ends_with_slash = clean_path.ends_with('/');
+        Some(
+            self.trailing_slash == TrailingSlash::Redirect
+                && ends_with_slash != self.has_trailing_slash,
+        )
+    }
+
+    /// Whether this route's original definition ended in a `/`.
+    pub fn has_trailing_slash(&self) -> bool {
+        self.has_trailing_slash
+    }
+
+    /// Strips the leading `/`, and - outside [`TrailingSlash::Strict`] - a
+    /// trailing `/` too, so the split parts line up with this route's
+    /// similarly-normalized stored segments (see [`Parser::route`]).
+    fn clean_path<'a>(&self, path: &'a str) -> &'a str {
+        let clean_path = path.strip_prefix('/').unwrap_or(path);
+        if self.trailing_slash == TrailingSlash::Strict {
+            clean_path
+        } else {
+            clean_path.strip_suffix('/').unwrap_or(clean_path)
+        }
     }
 
     /// If a path matches the route, returns the matching params. Otherwise,
@@ -92,15 +166,74 @@ impl Route {
     /// assert_eq!(route.parse_params("/user/123/"), Some(vec!["123".to_string()]));
     /// ```
     pub fn parse_params(&self, path: &str) -> Option<Vec<String>> {
-        let clean_path: &str = path.strip_prefix('/').unwrap_or(path);
+        let clean_path: &str = self.clean_path(path);
         let parts = clean_path.split('/').collect::<Vec<&str>>();
 
-        if parts.len() != self.path.len() {
+        let mut params = Vec::new();
+        let mut parts = parts.iter();
+        for segment in self.path.iter() {
+            if let Segment::CatchAll(_) = segment {
+                let rest = parts.by_ref().copied().collect::<Vec<&str>>().join("/");
+                params.push(self.quoter.decode(&rest));
+                return Some(params);
+            }
+
+            let part = parts.next()?;
+
+            match segment {
+                Segment::Empty => {
+                    if !part.is_empty() {
+                        return None;
+                    }
+                }
+                Segment::Constant(s) => {
+                    if part != s {
+                        return None;
+                    }
+                }
+                Segment::Param(_) => {
+                    params.push(self.quoter.decode(part));
+                }
+                Segment::CatchAll(_) => unreachable!("handled above"),
+            }
+        }
+
+        if parts.next().is_some() {
             return None;
         }
 
-        let mut params = Vec::new();
-        for (part, segment) in parts.iter().zip(self.path.iter()) {
+        Some(params)
+    }
+
+    /// Like [`Route::parse_params`], but keyed by each param's `name` rather
+    /// than position, so callers don't need to know segment order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use routem::{Parser, Route};
+    ///
+    /// let parser = Parser::default();
+    /// let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+    ///
+    /// let params = route.parse_params_named("/user/123/").unwrap();
+    /// assert_eq!(params.get("id"), Some(&"123".to_string()));
+    /// ```
+    pub fn parse_params_named(&self, path: &str) -> Option<HashMap<String, String>> {
+        let clean_path: &str = self.clean_path(path);
+        let parts = clean_path.split('/').collect::<Vec<&str>>();
+
+        let mut params = HashMap::new();
+        let mut parts = parts.iter();
+        for segment in self.path.iter() {
+            if let Segment::CatchAll(p) = segment {
+                let rest = parts.by_ref().copied().collect::<Vec<&str>>().join("/");
+                params.insert(p.name.clone(), self.quoter.decode(&rest));
+                return Some(params);
+            }
+
+            let part = parts.next()?;
+
             match segment {
                 Segment::Empty => {
                     if !part.is_empty() {
@@ -112,12 +245,17 @@ impl Route {
                         return None;
                     }
                 }
-                Segment::Param(_) => {
-                    params.push(part.to_string());
+                Segment::Param(p) => {
+                    params.insert(p.name.clone(), self.quoter.decode(part));
                 }
+                Segment::CatchAll(_) => unreachable!("handled above"),
             }
         }
 
+        if parts.next().is_some() {
+            return None;
+        }
+
         Some(params)
     }
 
@@ -164,6 +302,16 @@ impl Route {
                     if index >= params.len() {
                         return None;
                     }
+                    path.push_str(&self.quoter.encode(&params[index]));
+                    index += 1;
+                }
+                Segment::CatchAll(_) => {
+                    if index >= params.len() {
+                        return None;
+                    }
+                    // The catch-all value is already joined with internal
+                    // slashes, so it's pushed verbatim rather than encoded -
+                    // those slashes are the path structure, not data.
                     path.push_str(&params[index]);
                     index += 1;
                 }
@@ -175,6 +323,80 @@ impl Route {
 
         Some(path)
     }
+
+    /// Like [`Route::fill`], but looks params up by name rather than
+    /// position. Returns `None` if any segment's param name is missing from
+    /// `params`; extra entries in `params` are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use routem::{Parser, Route};
+    /// use std::collections::HashMap;
+    ///
+    /// let parser = Parser::default();
+    /// let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+    ///
+    /// let mut params = HashMap::new();
+    /// params.insert("id".to_string(), "123".to_string());
+    /// assert_eq!(route.fill_named(&params), Some("/user/123/".to_string()));
+    ///
+    /// assert_eq!(route.fill_named(&HashMap::new()), None);
+    /// ```
+    pub fn fill_named(&self, params: &HashMap<String, String>) -> Option<String> {
+        let mut path = String::new();
+
+        for segment in self.path.iter() {
+            path.push('/');
+            match segment {
+                Segment::Empty => {}
+                Segment::Constant(s) => {
+                    path.push_str(s);
+                }
+                Segment::Param(p) => {
+                    path.push_str(&self.quoter.encode(params.get(&p.name)?));
+                }
+                Segment::CatchAll(p) => {
+                    path.push_str(params.get(&p.name)?);
+                }
+            }
+        }
+
+        Some(path)
+    }
+
+    /// Mounts `other` under this route as a shared prefix, analogous to
+    /// actix-router's `ResourceDef::join`, so sub-modules can define routes
+    /// relative to a prefix that's assembled elsewhere (see
+    /// [`crate::Routes::mount`]). The prefix's params come first, in order,
+    /// followed by `other`'s; [`Route::fill`] on the result expects the
+    /// combined param count.
+    ///
+    /// # Examples
+    /// ```
+    /// use routem::{Parser, Route};
+    ///
+    /// let parser = Parser::default();
+    /// let prefix = parser.route("api", "/api").unwrap();
+    /// let users = parser.route("users", "/users/<id:int>/").unwrap();
+    ///
+    /// let mounted = prefix.join(&users);
+    /// assert!(mounted.check("/api/users/123/"));
+    /// ```
+    pub fn join(&self, other: &Route) -> Route {
+        let mut path = self.path.clone();
+        if path.len() > 1 && matches!(path.last(), Some(Segment::Empty)) {
+            path.pop();
+        }
+        path.extend(other.path.iter().cloned());
+
+        Route {
+            name: other.name.clone(),
+            path,
+            quoter: other.quoter.clone(),
+            trailing_slash: other.trailing_slash,
+            has_trailing_slash: other.has_trailing_slash,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -254,4 +476,256 @@ mod tests {
         assert!(!route.check("/user/123"));
         assert!(!route.check("/user/abc/"));
     }
+
+    #[test]
+    fn test_parse_catch_all_route() {
+        let input = "/files/<rest:*>";
+        let expected = vec![
+            Segment::Constant("files".to_string()),
+            Segment::CatchAll(Param {
+                name: "rest".to_string(),
+                kind: param_type::STRING_PARAM,
+            }),
+        ];
+        let name = "static-files";
+        let parser = Parser::default();
+
+        let route = parser.route(name, input);
+        assert!(route.is_ok(), "{:#?}", route);
+        let route = route.unwrap();
+
+        assert_eq!(route.name, name);
+        assert_eq!(route.path, expected);
+    }
+
+    #[test]
+    fn test_catch_all_must_be_last() {
+        let parser = Parser::default();
+        let route = parser.route("bad", "/files/<rest:*>/trailing");
+        assert!(route.is_err(), "{:#?}", route);
+    }
+
+    #[test]
+    fn test_check_catch_all_route() {
+        let parser = Parser::default();
+        let route = parser.route("static-files", "/files/<rest:*>").unwrap();
+
+        assert!(route.check("/files/css/app.css"));
+        assert!(route.check("/files/app.css"));
+        assert!(route.check("/files/"));
+        assert!(!route.check("/other/app.css"));
+    }
+
+    #[test]
+    fn test_parse_params_catch_all() {
+        let parser = Parser::default();
+        let route = parser.route("static-files", "/files/<rest:*>").unwrap();
+
+        assert_eq!(
+            route.parse_params("/files/css/app.css"),
+            Some(vec!["css/app.css".to_string()])
+        );
+        assert_eq!(route.parse_params("/files/"), Some(vec!["".to_string()]));
+        assert_eq!(route.parse_params("/other/app.css"), None);
+    }
+
+    #[test]
+    fn test_fill_catch_all() {
+        let parser = Parser::default();
+        let route = parser.route("static-files", "/files/<rest:*>").unwrap();
+
+        let params = vec!["css/app.css".to_string()];
+        assert_eq!(route.fill(&params), Some("/files/css/app.css".to_string()));
+    }
+
+    #[test]
+    fn test_parse_params_named() {
+        let parser = Parser::default();
+        let route = parser
+            .route("long-route", "/user/<id:int>/profile/<profile_id:uuid>")
+            .unwrap();
+
+        let params = route
+            .parse_params_named("/user/123/profile/36be8705-6c31-45d7-9321-d56cc07b50d9")
+            .unwrap();
+        assert_eq!(params.get("id"), Some(&"123".to_string()));
+        assert_eq!(
+            params.get("profile_id"),
+            Some(&"36be8705-6c31-45d7-9321-d56cc07b50d9".to_string())
+        );
+        assert_eq!(params.len(), 2);
+
+        assert_eq!(route.parse_params_named("/user/123/"), None);
+    }
+
+    #[test]
+    fn test_parse_params_named_catch_all() {
+        let parser = Parser::default();
+        let route = parser.route("static-files", "/files/<rest:*>").unwrap();
+
+        let params = route.parse_params_named("/files/css/app.css").unwrap();
+        assert_eq!(params.get("rest"), Some(&"css/app.css".to_string()));
+    }
+
+    #[test]
+    fn test_fill_named() {
+        let parser = Parser::default();
+        let route = parser
+            .route("long-route", "/user/<id:int>/profile/<profile_id:uuid>")
+            .unwrap();
+
+        let mut params = HashMap::new();
+        params.insert("id".to_string(), "123".to_string());
+        params.insert("profile_id".to_string(), "abc".to_string());
+        params.insert("extra".to_string(), "ignored".to_string());
+
+        assert_eq!(
+            route.fill_named(&params),
+            Some("/user/123/profile/abc".to_string())
+        );
+
+        params.remove("profile_id");
+        assert_eq!(route.fill_named(&params), None);
+    }
+
+    #[test]
+    fn test_parse_params_percent_decodes() {
+        let parser = Parser::default();
+        let route = parser.route("user-route", "/user/<name:string>/").unwrap();
+
+        assert_eq!(
+            route.parse_params("/user/john%20doe/"),
+            Some(vec!["john doe".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parse_params_leaves_protected_byte_encoded() {
+        let parser = Parser::default();
+        let route = parser.route("files", "/files/<name:string>/").unwrap();
+
+        // `%2F` stays encoded rather than silently becoming part of the
+        // path's segment structure.
+        assert_eq!(
+            route.parse_params("/files/a%2Fb/"),
+            Some(vec!["a%2Fb".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_fill_percent_encodes_params() {
+        let parser = Parser::default();
+        let route = parser.route("user-route", "/user/<name:string>/").unwrap();
+
+        let params = vec!["john doe".to_string()];
+        assert_eq!(route.fill(&params), Some("/user/john%20doe/".to_string()));
+    }
+
+    #[test]
+    fn test_trailing_slash_strict_distinguishes_variants() {
+        let parser = Parser::default();
+        let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+
+        assert!(route.check("/user/123/"));
+        assert!(!route.check("/user/123"));
+    }
+
+    #[test]
+    fn test_trailing_slash_ignore_matches_both_variants() {
+        let mut parser = Parser::default();
+        parser.set_trailing_slash(TrailingSlash::Ignore);
+        let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+
+        assert!(route.check("/user/123/"));
+        assert!(route.check("/user/123"));
+        assert_eq!(
+            route.parse_params("/user/123"),
+            Some(vec!["123".to_string()])
+        );
+        assert_eq!(
+            route.parse_params("/user/123/"),
+            Some(vec!["123".to_string()])
+        );
+
+        let no_slash_route = parser.route("club-route", "/club/<id:int>").unwrap();
+        assert!(no_slash_route.check("/club/123"));
+        assert!(no_slash_route.check("/club/123/"));
+    }
+
+    #[test]
+    fn test_trailing_slash_ignore_keeps_root_route() {
+        let mut parser = Parser::default();
+        parser.set_trailing_slash(TrailingSlash::Ignore);
+        let route = parser.route("root", "/").unwrap();
+
+        assert_eq!(route.path, vec![Segment::Empty]);
+        assert!(route.check("/"));
+    }
+
+    #[test]
+    fn test_trailing_slash_redirect_reports_canonical_form() {
+        let mut parser = Parser::default();
+        parser.set_trailing_slash(TrailingSlash::Redirect);
+        let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+
+        assert_eq!(route.check_redirect("/user/123/"), Some(false));
+        assert_eq!(route.check_redirect("/user/123"), Some(true));
+        assert_eq!(route.check_redirect("/user/abc/"), None);
+        assert!(route.has_trailing_slash());
+
+        let no_slash_route = parser.route("club-route", "/club/<id:int>").unwrap();
+        assert_eq!(no_slash_route.check_redirect("/club/123"), Some(false));
+        assert_eq!(no_slash_route.check_redirect("/club/123/"), Some(true));
+        assert!(!no_slash_route.has_trailing_slash());
+    }
+
+    #[test]
+    fn test_join_drops_redundant_trailing_empty_segment() {
+        let parser = Parser::default();
+        let prefix = parser.route("api", "/api/").unwrap();
+        let users = parser.route("users", "/users/<id:int>/").unwrap();
+
+        let mounted = prefix.join(&users);
+        assert_eq!(
+            mounted.path,
+            vec![
+                Segment::Constant("api".to_string()),
+                Segment::Constant("users".to_string()),
+                Segment::Param(Param {
+                    name: "id".to_string(),
+                    kind: param_type::INT_PARAM,
+                }),
+                Segment::Empty,
+            ]
+        );
+        assert!(mounted.check("/api/users/123/"));
+        assert!(!mounted.check("/api/users/123"));
+    }
+
+    #[test]
+    fn test_join_without_trailing_slash_on_prefix() {
+        let parser = Parser::default();
+        let prefix = parser.route("api", "/api").unwrap();
+        let users = parser.route("users", "/users/<id:int>/").unwrap();
+
+        let mounted = prefix.join(&users);
+        assert!(mounted.check("/api/users/123/"));
+    }
+
+    #[test]
+    fn test_join_merges_params_in_order() {
+        let parser = Parser::default();
+        let prefix = parser.route("org", "/org/<org_id:int>").unwrap();
+        let users = parser.route("users", "/users/<id:int>/").unwrap();
+
+        let mounted = prefix.join(&users);
+        assert_eq!(
+            mounted.parse_params("/org/1/users/2/"),
+            Some(vec!["1".to_string(), "2".to_string()])
+        );
+        assert_eq!(
+            mounted.fill(&["1".to_string(), "2".to_string()]),
+            Some("/org/1/users/2/".to_string())
+        );
+    }
 }
@@ -0,0 +1,343 @@
+//! Serde support for extracting a route's matched params into a typed
+//! value, gated behind the `serde` feature (not yet wired up in a
+//! `Cargo.toml`, since this tree doesn't have one to add the optional
+//! `serde` dependency to). Modeled on actix-router's `de.rs`: a struct maps
+//! each field to the param of the same name, a tuple/seq maps positionally
+//! in the same order as [`Route::parse_params`], and a route with exactly
+//! one param can deserialize straight into a newtype.
+
+use std::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+
+use super::{Route, Segment};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PathDeserializeError {
+    #[error("path `{0}` does not match the route")]
+    NoMatch(String),
+    #[error("route has {actual} param(s), but {expected} were expected")]
+    ParamCountMismatch { expected: usize, actual: usize },
+    #[error("failed to parse param value `{value}`: {message}")]
+    InvalidValue { value: String, message: String },
+    #[error("{0}")]
+    Message(String),
+}
+
+impl de::Error for PathDeserializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        PathDeserializeError::Message(msg.to_string())
+    }
+}
+
+impl Route {
+    /// Matches `path` against this route and deserializes its captured
+    /// params into `T`, so callers can do
+    /// `let Params { id, .. } = route.deserialize(path)?;` instead of
+    /// indexing [`Route::parse_params`]'s `Vec<String>`.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use routem::Parser;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Params {
+    ///     id: i64,
+    /// }
+    ///
+    /// let parser = Parser::default();
+    /// let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+    /// let params: Params = route.deserialize("/user/123/").unwrap();
+    /// assert_eq!(params.id, 123);
+    /// ```
+    pub fn deserialize<'de, T: de::Deserialize<'de>>(
+        &self,
+        path: &str,
+    ) -> Result<T, PathDeserializeError> {
+        if !self.check(path) {
+            return Err(PathDeserializeError::NoMatch(path.to_string()));
+        }
+
+        let names = param_names(self);
+        let values = self
+            .parse_params(path)
+            .ok_or_else(|| PathDeserializeError::NoMatch(path.to_string()))?;
+
+        if names.len() != values.len() {
+            return Err(PathDeserializeError::ParamCountMismatch {
+                expected: names.len(),
+                actual: values.len(),
+            });
+        }
+
+        let params: Vec<(&str, String)> = names.into_iter().zip(values).collect();
+        T::deserialize(PathDeserializer { params: &params })
+    }
+}
+
+fn param_names(route: &Route) -> Vec<&str> {
+    route
+        .path
+        .iter()
+        .filter_map(|segment| match segment {
+            Segment::Param(p) | Segment::CatchAll(p) => Some(p.name.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+struct PathDeserializer<'a> {
+    params: &'a [(&'a str, String)],
+}
+
+impl<'de, 'a> Deserializer<'de> for PathDeserializer<'a> {
+    type Error = PathDeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(ParamMapAccess {
+            params: self.params,
+            index: 0,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(ParamSeqAccess {
+            params: self.params,
+            index: 0,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.params.len() != 1 {
+            return Err(PathDeserializeError::ParamCountMismatch {
+                expected: 1,
+                actual: self.params.len(),
+            });
+        }
+        visitor.visit_newtype_struct(ParamValue(&self.params[0].1))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct enum identifier ignored_any
+    }
+}
+
+struct ParamMapAccess<'a> {
+    params: &'a [(&'a str, String)],
+    index: usize,
+}
+
+impl<'de, 'a> MapAccess<'de> for ParamMapAccess<'a> {
+    type Error = PathDeserializeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.params.get(self.index) {
+            Some((name, _)) => seed.deserialize(ParamValue(name)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        let (_, value) = &self.params[self.index];
+        self.index += 1;
+        seed.deserialize(ParamValue(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.params.len() - self.index)
+    }
+}
+
+struct ParamSeqAccess<'a> {
+    params: &'a [(&'a str, String)],
+    index: usize,
+}
+
+impl<'de, 'a> SeqAccess<'de> for ParamSeqAccess<'a> {
+    type Error = PathDeserializeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        match self.params.get(self.index) {
+            Some((_, value)) => {
+                self.index += 1;
+                seed.deserialize(ParamValue(value)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.params.len() - self.index)
+    }
+}
+
+/// Deserializes a single captured param value, parsing it via the target
+/// type's `FromStr` for scalars (e.g. the int/uuid `ParamType::check`
+/// validation composes naturally with a strongly-typed `i64`/`Uuid` field)
+/// and handing it to serde as a string otherwise.
+struct ParamValue<'a>(&'a str);
+
+macro_rules! deserialize_parsed {
+    ($($trait_fn:ident => $visit_fn:ident : $ty:ty),+ $(,)?) => {
+        $(
+            fn $trait_fn<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let parsed = self.0.parse::<$ty>().map_err(|e| PathDeserializeError::InvalidValue {
+                    value: self.0.to_string(),
+                    message: e.to_string(),
+                })?;
+                visitor.$visit_fn(parsed)
+            }
+        )+
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for ParamValue<'a> {
+    type Error = PathDeserializeError;
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.0.to_string())
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    forward_to_deserialize_any! {
+        char bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any i128 u128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::Parser;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct UserProfileParams {
+        id: i64,
+        profile_id: String,
+    }
+
+    #[test]
+    fn test_deserialize_struct() {
+        let parser = Parser::default();
+        let route = parser
+            .route("long-route", "/user/<id:int>/profile/<profile_id:uuid>")
+            .unwrap();
+
+        let params: UserProfileParams = route
+            .deserialize("/user/123/profile/36be8705-6c31-45d7-9321-d56cc07b50d9")
+            .unwrap();
+
+        assert_eq!(
+            params,
+            UserProfileParams {
+                id: 123,
+                profile_id: "36be8705-6c31-45d7-9321-d56cc07b50d9".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_tuple() {
+        let parser = Parser::default();
+        let route = parser
+            .route("long-route", "/user/<id:int>/profile/<profile_id:uuid>")
+            .unwrap();
+
+        let (id, profile_id): (i64, String) = route
+            .deserialize("/user/123/profile/36be8705-6c31-45d7-9321-d56cc07b50d9")
+            .unwrap();
+
+        assert_eq!(id, 123);
+        assert_eq!(profile_id, "36be8705-6c31-45d7-9321-d56cc07b50d9");
+    }
+
+    #[test]
+    fn test_deserialize_newtype() {
+        let parser = Parser::default();
+        let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Id(i64);
+
+        let id: Id = route.deserialize("/user/123/").unwrap();
+        assert_eq!(id, Id(123));
+    }
+
+    #[test]
+    fn test_deserialize_no_match() {
+        let parser = Parser::default();
+        let route = parser.route("user-route", "/user/<id:int>/").unwrap();
+
+        let result: Result<UserProfileParams, _> = route.deserialize("/user/abc/");
+        assert!(result.is_err());
+    }
+}
@@ -11,10 +11,12 @@ use thiserror::Error;
 
 use crate::Route;
 
-use super::{param_type::ParamMap, Param, Segment, ParamType};
+use super::{param_type, param_type::ParamMap, Param, ParamType, Quoter, Segment, TrailingSlash};
 
 pub struct Parser {
     param_types: ParamMap,
+    quoter: Quoter,
+    trailing_slash: TrailingSlash,
 }
 
 #[derive(Error, Debug)]
@@ -25,6 +27,9 @@ pub enum ParseError {
         remainder: String,
     },
 
+    #[error("catch-all segment must be the last segment in the route")]
+    CatchAllNotLast { segments: Vec<Segment> },
+
     #[error("parse error: {0}")]
     Other(String),
 }
@@ -34,19 +39,37 @@ impl Default for Parser {
     fn default() -> Self {
         Self {
             param_types: crate::route::param_type::DEFAULT_PARAM_TYPES.clone(),
+            quoter: Quoter::default(),
+            trailing_slash: TrailingSlash::default(),
         }
     }
 }
 
 impl Parser {
     pub fn new(param_types: ParamMap) -> Self {
-        Self { param_types }
+        Self {
+            param_types,
+            quoter: Quoter::default(),
+            trailing_slash: TrailingSlash::default(),
+        }
     }
 
     pub fn add_param_type(&mut self, param_type: ParamType) {
         self.param_types.insert(param_type.typename, param_type);
     }
 
+    /// Sets the [`Quoter`] used to percent-decode/encode captured param
+    /// values for routes built by this parser afterwards.
+    pub fn set_quoter(&mut self, quoter: Quoter) {
+        self.quoter = quoter;
+    }
+
+    /// Sets the [`TrailingSlash`] policy used by routes built by this parser
+    /// afterwards.
+    pub fn set_trailing_slash(&mut self, trailing_slash: TrailingSlash) {
+        self.trailing_slash = trailing_slash;
+    }
+
     /// Parse a route from a string.
     ///
     /// # Examples
@@ -68,9 +91,36 @@ impl Parser {
             Err(e) => return Err(ParseError::Other(e.to_string())),
         };
 
+        if let Some(pos) = segments.iter().position(|s| matches!(s, Segment::CatchAll(_))) {
+            if pos != segments.len() - 1 {
+                return Err(ParseError::CatchAllNotLast { segments });
+            }
+        }
+
+        let has_trailing_slash = matches!(segments.last(), Some(Segment::Empty));
+
+        // Outside `Strict` mode, a route's trailing `/` is cosmetic: drop it
+        // from the stored segments so `Route::check`/`parse_params` (which
+        // normalize the incoming path the same way) can match both slash
+        // variants against one segment list. The bare root route ("/") is
+        // never trimmed, since that would leave it with no segments at all.
+        let segments = if self.trailing_slash != TrailingSlash::Strict
+            && has_trailing_slash
+            && segments.len() > 1
+        {
+            let mut segments = segments;
+            segments.pop();
+            segments
+        } else {
+            segments
+        };
+
         Ok(Route {
             name: name.to_string(),
             path: segments,
+            quoter: self.quoter.clone(),
+            trailing_slash: self.trailing_slash,
+            has_trailing_slash,
         })
     }
 }
@@ -93,6 +143,18 @@ impl Parser {
 
         let (input, name) = identifier(input)?;
         let (input, _) = tag(":")(input)?;
+
+        if let Ok((input, _)) = tag::<_, _, Error<&str>>("*")(input) {
+            let (input, _) = tag(">")(input)?;
+            return Ok((
+                input,
+                Segment::CatchAll(Param {
+                    name: name.to_string(),
+                    kind: param_type::STRING_PARAM,
+                }),
+            ));
+        }
+
         let (input, kind) = urlsafe_str(input)?;
         let kind = if let Some(param_type) = self.param_types.get(kind) {
             param_type.clone()
@@ -173,6 +235,21 @@ mod tests {
         assert_eq!(output, expected);
     }
 
+    #[test]
+    fn test_segment_catch_all() {
+        let input = "<rest:*>";
+        let expected = Segment::CatchAll(Param {
+            name: "rest".to_string(),
+            kind: param_type::STRING_PARAM,
+        });
+
+        let parser = Parser::default();
+
+        let (input, output) = parser.segment(input).unwrap();
+        assert_eq!(input, "");
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_parse_custom_type() {
         fn return_true(_: &str) -> bool {
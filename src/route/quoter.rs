@@ -0,0 +1,120 @@
+/// Percent-decodes/encodes captured param values, the way actix-router's
+/// `Quoter` does. A set of "protected" bytes can be configured to stay
+/// percent-encoded when decoding, so a `%2F` inside a segment isn't
+/// silently turned into a literal `/` and mistaken for a path separator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quoter {
+    protected: Vec<u8>,
+}
+
+impl Quoter {
+    /// Builds a `Quoter` that leaves `protected` bytes percent-encoded
+    /// rather than decoding them.
+    pub fn new(protected: &[u8]) -> Self {
+        Quoter {
+            protected: protected.to_vec(),
+        }
+    }
+
+    /// Percent-decodes `input`, leaving any `%XX` escape of a protected
+    /// byte untouched.
+    pub fn decode(&self, input: &str) -> String {
+        let bytes = input.as_bytes();
+        let mut out = Vec::with_capacity(bytes.len());
+
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' && i + 2 < bytes.len() {
+                if let Some(byte) = decode_hex_pair(bytes[i + 1], bytes[i + 2]) {
+                    if self.protected.contains(&byte) {
+                        out.extend_from_slice(&bytes[i..i + 3]);
+                    } else {
+                        out.push(byte);
+                    }
+                    i += 3;
+                    continue;
+                }
+            }
+
+            out.push(bytes[i]);
+            i += 1;
+        }
+
+        String::from_utf8_lossy(&out).into_owned()
+    }
+
+    /// Percent-encodes `input` so that it round-trips back through
+    /// [`Quoter::decode`] to the same value, even when it contains bytes
+    /// that aren't safe to place directly in a path. Protected bytes are
+    /// the exception: they are encoded here, but `decode` leaves them
+    /// percent-encoded.
+    pub fn encode(&self, input: &str) -> String {
+        let mut out = String::with_capacity(input.len());
+
+        for byte in input.bytes() {
+            if is_unreserved(byte) {
+                out.push(byte as char);
+            } else {
+                out.push('%');
+                out.push_str(&format!("{byte:02X}"));
+            }
+        }
+
+        out
+    }
+}
+
+impl Default for Quoter {
+    fn default() -> Self {
+        // `/` stays percent-encoded on decode so a captured param can never
+        // be mistaken for an extra path segment boundary.
+        Quoter::new(b"/")
+    }
+}
+
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~')
+}
+
+fn decode_hex_pair(hi: u8, lo: u8) -> Option<u8> {
+    Some((hex_digit(hi)? << 4) | hex_digit(lo)?)
+}
+
+fn hex_digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_basic() {
+        let quoter = Quoter::default();
+        assert_eq!(quoter.decode("john%20doe"), "john doe");
+    }
+
+    #[test]
+    fn test_decode_leaves_protected_byte_encoded() {
+        let quoter = Quoter::default();
+        assert_eq!(quoter.decode("a%2Fb"), "a%2Fb");
+    }
+
+    #[test]
+    fn test_encode_round_trips() {
+        let quoter = Quoter::default();
+        let decoded = quoter.decode("john%20doe");
+        assert_eq!(quoter.encode(&decoded), "john%20doe");
+    }
+
+    #[test]
+    fn test_encode_escapes_protected_byte() {
+        let quoter = Quoter::default();
+        assert_eq!(quoter.encode("a/b"), "a%2Fb");
+    }
+}
@@ -0,0 +1,16 @@
+/// How a route's trailing slash is treated when matching, analogous to
+/// Rocket's and leptos's trailing-slash normalization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrailingSlash {
+    /// `/user/<id:int>/` and `/user/<id:int>` are distinct routes (today's
+    /// behavior).
+    #[default]
+    Strict,
+    /// A route matches whether or not the request has a trailing slash.
+    Ignore,
+    /// Like `Ignore`, but [`crate::Route::check_redirect`] and
+    /// [`crate::Routes::find_redirect`] report when the request's slash
+    /// doesn't match the route's canonical form, so the caller can 301 to
+    /// it.
+    Redirect,
+}
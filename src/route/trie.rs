@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use super::{Param, Route, Segment};
+
+/// A node in the radix trie used by [`crate::Routes`] to match paths in
+/// roughly O(segments) instead of scanning every registered route.
+///
+/// Each node represents one path segment. Constant children are keyed by
+/// their literal text (an empty string represents [`Segment::Empty`]), while
+/// parametric children are tried in insertion order after the constants,
+/// since a literal match should always win over a param that happens to
+/// also validate.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct Node {
+    constants: HashMap<String, Node>,
+    params: Vec<(Param, Node)>,
+    catch_all: Option<Route>,
+    route: Option<Route>,
+}
+
+impl Node {
+    pub(crate) fn insert(&mut self, segments: &[Segment], route: Route) {
+        match segments.split_first() {
+            None => self.route = Some(route),
+            Some((Segment::Empty, rest)) => {
+                self.constants.entry(String::new()).or_default().insert(rest, route)
+            }
+            Some((Segment::Constant(s), rest)) => {
+                self.constants.entry(s.clone()).or_default().insert(rest, route)
+            }
+            Some((Segment::Param(p), rest)) => {
+                if let Some((_, child)) = self.params.iter_mut().find(|(existing, _)| existing == p) {
+                    child.insert(rest, route);
+                } else {
+                    let mut child = Node::default();
+                    child.insert(rest, route);
+                    self.params.push((p.clone(), child));
+                }
+            }
+            // The parser guarantees a catch-all is the last segment of a
+            // parsed route; any segments after one (as `Route::join` can
+            // produce from a catch-all prefix) are not inserted.
+            Some((Segment::CatchAll(_), _rest)) => self.catch_all = Some(route),
+        }
+    }
+
+    /// Collects every [`Route`] stored in this subtree, for [`crate::Routes::mount`]
+    /// to join onto a prefix and re-insert.
+    pub(crate) fn routes(&self) -> Vec<&Route> {
+        let mut routes = Vec::new();
+        routes.extend(self.route.as_ref());
+        routes.extend(self.catch_all.as_ref());
+        for child in self.constants.values() {
+            routes.extend(child.routes());
+        }
+        for (_, child) in &self.params {
+            routes.extend(child.routes());
+        }
+        routes
+    }
+
+    pub(crate) fn find(&self, parts: &[&str]) -> Option<&Route> {
+        match parts.split_first() {
+            None => self.route.as_ref().or(self.catch_all.as_ref()),
+            Some((part, rest)) => {
+                if let Some(child) = self.constants.get(*part) {
+                    if let Some(route) = child.find(rest) {
+                        return Some(route);
+                    }
+                }
+
+                for (param, child) in &self.params {
+                    if (param.kind.check)(part) {
+                        if let Some(route) = child.find(rest) {
+                            return Some(route);
+                        }
+                    }
+                }
+
+                self.catch_all.as_ref()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn test_constant_wins_over_param() {
+        let parser = Parser::default();
+        let me_route = parser.route("user-me", "/user/me/").unwrap();
+        let id_route = parser.route("user-by-id", "/user/<id:string>/").unwrap();
+
+        let mut root = Node::default();
+        root.insert(&me_route.path, me_route.clone());
+        root.insert(&id_route.path, id_route.clone());
+
+        assert_eq!(root.find(&["user", "me", ""]), Some(&me_route));
+        assert_eq!(root.find(&["user", "anyone", ""]), Some(&id_route));
+    }
+
+    #[test]
+    fn test_param_kind_still_checked() {
+        let parser = Parser::default();
+        let route = parser.route("user-by-id", "/user/<id:int>/").unwrap();
+
+        let mut root = Node::default();
+        root.insert(&route.path, route.clone());
+
+        assert_eq!(root.find(&["user", "123", ""]), Some(&route));
+        assert_eq!(root.find(&["user", "abc", ""]), None);
+    }
+
+    #[test]
+    fn test_catch_all_matches_remainder() {
+        let parser = Parser::default();
+        let route = parser.route("static-files", "/files/<rest:*>").unwrap();
+
+        let mut root = Node::default();
+        root.insert(&route.path, route.clone());
+
+        assert_eq!(root.find(&["files", "css", "app.css"]), Some(&route));
+        assert_eq!(root.find(&["files", ""]), Some(&route));
+        assert_eq!(root.find(&["other", "app.css"]), None);
+    }
+}